@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use lru::LruCache;
+use pyo3::pyclass;
+use sha2::{Digest, Sha256};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, OnceLock};
+use wren_core::AnalyzedWrenMDL;
+
+use crate::errors::CoreError;
+
+/// Default capacity for both the process-level `AnalyzedWrenMDL` cache and a fresh
+/// `TransformSqlCache`, chosen to keep memory bounded for long-running servers without
+/// needing a config to get a reasonable default.
+pub const DEFAULT_TRANSFORM_CACHE_CAPACITY: usize = 128;
+
+/// SHA-256 digest of the decoded manifest bytes, used as a stable cache key for
+/// `AnalyzedWrenMDL::analyze` independent of base64 re-encoding.
+pub fn manifest_digest(manifest_bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(manifest_bytes).into()
+}
+
+/// Decode `mdl_base64` (the same encoding `manifest::to_manifest` decodes) and digest
+/// the resulting bytes, so the cache key is the digest of the manifest itself rather
+/// than of its base64 text. `BASE64_STANDARD` requires canonical padding and rejects
+/// line breaks, so such input is a decode error here, not a cache hit.
+pub fn manifest_digest_from_base64(mdl_base64: &str) -> Result<[u8; 32], CoreError> {
+    let manifest_bytes = BASE64_STANDARD.decode(mdl_base64).map_err(CoreError::from)?;
+    Ok(manifest_digest(&manifest_bytes))
+}
+
+/// Process-wide cache of analyzed manifests, keyed by `manifest_digest`. Shared across
+/// every `PySessionContext` so repeated `new(mdl_base64)` calls with the same manifest
+/// skip re-analysis.
+static MDL_CACHE: OnceLock<Mutex<LruCache<[u8; 32], Arc<AnalyzedWrenMDL>>>> =
+    OnceLock::new();
+
+fn mdl_cache() -> &'static Mutex<LruCache<[u8; 32], Arc<AnalyzedWrenMDL>>> {
+    MDL_CACHE.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(DEFAULT_TRANSFORM_CACHE_CAPACITY).unwrap(),
+        ))
+    })
+}
+
+/// Look up an already-analyzed manifest by digest.
+pub fn get_analyzed_mdl(digest: &[u8; 32]) -> Option<Arc<AnalyzedWrenMDL>> {
+    mdl_cache().lock().unwrap().get(digest).cloned()
+}
+
+/// Record a newly-analyzed manifest under its digest.
+pub fn put_analyzed_mdl(digest: [u8; 32], analyzed_mdl: Arc<AnalyzedWrenMDL>) {
+    mdl_cache().lock().unwrap().put(digest, analyzed_mdl);
+}
+
+/// Drop every entry from the process-level `AnalyzedWrenMDL` cache.
+pub fn clear_mdl_cache() {
+    mdl_cache().lock().unwrap().clear();
+}
+
+fn mdl_cache_len() -> usize {
+    mdl_cache().lock().unwrap().len()
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct TransformCacheKey {
+    mdl_hash: u64,
+    remote_functions_hash: u64,
+    target_dialect: Option<String>,
+    sql: String,
+}
+
+/// Per-`PySessionContext` memoization of `transform_sql`, keyed on a stable hash of
+/// `(mdl, remote_functions)` plus the dialect and input SQL. Re-running the
+/// analyze/plan pipeline for SQL the caller has already transformed is pure overhead.
+pub struct TransformSqlCache {
+    entries: Mutex<LruCache<TransformCacheKey, String>>,
+    hits: std::sync::atomic::AtomicUsize,
+    misses: std::sync::atomic::AtomicUsize,
+}
+
+impl TransformSqlCache {
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity)
+            .unwrap_or_else(|| NonZeroUsize::new(DEFAULT_TRANSFORM_CACHE_CAPACITY).unwrap());
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            hits: std::sync::atomic::AtomicUsize::new(0),
+            misses: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    pub fn get_or_insert_with(
+        &self,
+        mdl_hash: u64,
+        remote_functions_hash: u64,
+        target_dialect: Option<&str>,
+        sql: &str,
+        compute: impl FnOnce() -> pyo3::PyResult<String>,
+    ) -> pyo3::PyResult<String> {
+        let key = TransformCacheKey {
+            mdl_hash,
+            remote_functions_hash,
+            target_dialect: target_dialect.map(str::to_string),
+            sql: sql.to_string(),
+        };
+        if let Some(cached) = self.entries.lock().unwrap().get(&key) {
+            self.hits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            return Ok(cached.clone());
+        }
+        self.misses.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let value = compute()?;
+        self.entries.lock().unwrap().put(key, value.clone());
+        Ok(value)
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+        self.hits.store(0, std::sync::atomic::Ordering::Relaxed);
+        self.misses.store(0, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            transform_cache_hits: self.hits.load(std::sync::atomic::Ordering::Relaxed),
+            transform_cache_misses: self
+                .misses
+                .load(std::sync::atomic::Ordering::Relaxed),
+            transform_cache_len: self.entries.lock().unwrap().len(),
+            mdl_cache_len: mdl_cache_len(),
+        }
+    }
+}
+
+/// Hash helper mirroring `PySessionContext`'s existing `Hash` impl, so the transform
+/// cache key stays in sync with what actually determines `transform_sql`'s output.
+pub fn stable_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Snapshot of cache hit/miss counters, returned by `PySessionContext::cache_stats`.
+#[pyclass(name = "CacheStats")]
+#[derive(Clone, Copy)]
+pub struct CacheStats {
+    #[pyo3(get)]
+    pub transform_cache_hits: usize,
+    #[pyo3(get)]
+    pub transform_cache_misses: usize,
+    #[pyo3(get)]
+    pub transform_cache_len: usize,
+    #[pyo3(get)]
+    pub mdl_cache_len: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_digest_from_base64_digests_the_decoded_bytes() {
+        // The point of the fix: the digest must match a digest of the *decoded*
+        // manifest bytes, not the base64 string itself.
+        let manifest_bytes = b"wren-manifest-bytes";
+        let encoded = BASE64_STANDARD.encode(manifest_bytes);
+
+        let digest_from_base64 = manifest_digest_from_base64(&encoded).unwrap();
+        let digest_from_raw_bytes = manifest_digest(manifest_bytes);
+        assert_eq!(digest_from_base64, digest_from_raw_bytes);
+
+        // Guard against regressing back to hashing the base64 string directly.
+        assert_ne!(digest_from_base64, manifest_digest(encoded.as_bytes()));
+    }
+
+    #[test]
+    fn manifest_digest_differs_for_different_bytes() {
+        let a = manifest_digest(b"manifest-a");
+        let b = manifest_digest(b"manifest-b");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn stable_hash_is_deterministic() {
+        assert_eq!(stable_hash(&"same input"), stable_hash(&"same input"));
+        assert_ne!(stable_hash(&"input a"), stable_hash(&"input b"));
+    }
+
+    #[test]
+    fn transform_sql_cache_hits_on_repeat_key() {
+        let cache = TransformSqlCache::with_capacity(4);
+        let mut computations = 0;
+        for _ in 0..3 {
+            cache
+                .get_or_insert_with(1, 2, Some("bigquery"), "SELECT 1", || {
+                    computations += 1;
+                    Ok("SELECT 1".to_string())
+                })
+                .unwrap();
+        }
+        assert_eq!(computations, 1);
+        assert_eq!(cache.stats().transform_cache_hits, 2);
+        assert_eq!(cache.stats().transform_cache_misses, 1);
+    }
+
+    #[test]
+    fn transform_sql_cache_misses_on_different_dialect() {
+        let cache = TransformSqlCache::with_capacity(4);
+        cache
+            .get_or_insert_with(1, 2, Some("bigquery"), "SELECT 1", || {
+                Ok("bigquery-sql".to_string())
+            })
+            .unwrap();
+        cache
+            .get_or_insert_with(1, 2, Some("postgres"), "SELECT 1", || {
+                Ok("postgres-sql".to_string())
+            })
+            .unwrap();
+        assert_eq!(cache.stats().transform_cache_len, 2);
+    }
+}
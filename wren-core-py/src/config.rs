@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::errors::CoreError;
+use pyo3::{pyclass, pymethods, PyResult};
+use std::sync::Arc;
+use wren_core::datafusion::execution::memory_pool::{FairSpillPool, GreedyMemoryPool};
+use wren_core::datafusion::execution::runtime_env::RuntimeEnvBuilder;
+use wren_core::datafusion::execution::runtime_env::RuntimeEnv;
+use wren_core::datafusion::prelude::SessionConfig;
+
+/// Runtime knobs for a `SessionContext`, mirroring the subset of DataFusion's
+/// `SessionConfig`/`RuntimeEnv` that matters once the crate actually executes queries
+/// rather than only rewriting SQL.
+#[pyclass(name = "SessionConfig")]
+#[derive(Clone, Default)]
+pub struct PySessionConfig {
+    memory_limit_bytes: Option<usize>,
+    /// `true` for a `FairSpillPool` (splits the limit evenly across concurrent
+    /// queries), `false` for a `GreedyMemoryPool` (first-come, first-served).
+    fair_memory_pool: bool,
+    target_partitions: Option<usize>,
+    query_timeout_secs: Option<u64>,
+    /// Capacity of the per-context `transform_sql` memoization cache. `None` uses the
+    /// cache's own default.
+    transform_cache_capacity: Option<usize>,
+}
+
+#[pymethods]
+impl PySessionConfig {
+    #[new]
+    #[pyo3(signature = (memory_limit_bytes=None, fair_memory_pool=false, target_partitions=None, query_timeout_secs=None, transform_cache_capacity=None))]
+    pub fn new(
+        memory_limit_bytes: Option<usize>,
+        fair_memory_pool: bool,
+        target_partitions: Option<usize>,
+        query_timeout_secs: Option<u64>,
+        transform_cache_capacity: Option<usize>,
+    ) -> Self {
+        Self {
+            memory_limit_bytes,
+            fair_memory_pool,
+            target_partitions,
+            query_timeout_secs,
+            transform_cache_capacity,
+        }
+    }
+}
+
+impl PySessionConfig {
+    pub fn query_timeout(&self) -> Option<std::time::Duration> {
+        self.query_timeout_secs.map(std::time::Duration::from_secs)
+    }
+
+    pub fn transform_cache_capacity(&self) -> Option<usize> {
+        self.transform_cache_capacity
+    }
+
+    /// Build the DataFusion `SessionConfig` this config describes.
+    pub fn to_session_config(&self) -> SessionConfig {
+        let mut config = SessionConfig::new();
+        if let Some(target_partitions) = self.target_partitions {
+            config = config.with_target_partitions(target_partitions);
+        }
+        config
+    }
+
+    /// Build the DataFusion `RuntimeEnv` this config describes, applying the memory
+    /// pool limit if one was set.
+    pub fn to_runtime_env(&self) -> PyResult<Arc<RuntimeEnv>> {
+        let mut builder = RuntimeEnvBuilder::new();
+        if let Some(limit) = self.memory_limit_bytes {
+            builder = if self.fair_memory_pool {
+                builder.with_memory_pool(Arc::new(FairSpillPool::new(limit)))
+            } else {
+                builder.with_memory_pool(Arc::new(GreedyMemoryPool::new(limit)))
+            };
+        }
+        Ok(Arc::new(builder.build().map_err(CoreError::from)?))
+    }
+}
@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::cache::{CacheStats, TransformSqlCache};
+use crate::config::PySessionConfig;
+use crate::dialect::render_in_dialect;
 use crate::errors::CoreError;
 use crate::manifest::to_manifest;
 use crate::remote_functions::PyRemoteFunction;
@@ -25,8 +28,11 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::ControlFlow;
 use std::sync::Arc;
+use wren_core::arrow::pyarrow::PyArrowType;
+use wren_core::arrow::record_batch::RecordBatch;
 use wren_core::ast::{visit_statements_mut, Expr, Statement, Value};
-use wren_core::dialect::GenericDialect;
+use wren_core::datafusion::execution::SendableRecordBatchStream;
+use wren_core::datafusion::logical_expr::TypeSignature;
 use wren_core::logical_plan::utils::map_data_type;
 use wren_core::mdl::context::create_ctx_with_mdl;
 use wren_core::mdl::function::{
@@ -34,6 +40,30 @@ use wren_core::mdl::function::{
     RemoteFunction,
 };
 use wren_core::{mdl, AggregateUDF, AnalyzedWrenMDL, ScalarUDF, WindowUDF};
+
+/// The MDL manifest schema version this build of `wren-core-py` was compiled against.
+/// Bump this alongside any breaking change to the manifest format.
+const MDL_MANIFEST_SCHEMA_VERSION: &str = "v1";
+
+/// Versions and feature flags reported by `PySessionContext::capabilities`, so
+/// downstream tools can detect a mismatch up front instead of failing mid-transform.
+#[pyclass(name = "Capabilities")]
+#[derive(Clone)]
+pub struct PyCapabilities {
+    #[pyo3(get)]
+    pub wren_core_version: String,
+    #[pyo3(get)]
+    pub datafusion_version: String,
+    #[pyo3(get)]
+    pub supported_dialects: Vec<String>,
+    #[pyo3(get)]
+    pub mdl_manifest_schema_version: String,
+    #[pyo3(get)]
+    pub execution_enabled: bool,
+    #[pyo3(get)]
+    pub remote_functions_enabled: bool,
+}
+
 /// The Python wrapper for the Wren Core session context.
 #[pyclass(name = "SessionContext")]
 #[derive(Clone)]
@@ -41,12 +71,35 @@ pub struct PySessionContext {
     ctx: wren_core::SessionContext,
     mdl: Arc<AnalyzedWrenMDL>,
     remote_functions: Vec<RemoteFunction>,
+    runtime: Arc<tokio::runtime::Runtime>,
+    /// The `sqlparser` dialect name, e.g. "bigquery". `None` uses `GenericDialect`.
+    /// `pushdown_limit` parses its input SQL under it; `transform_sql` parses the
+    /// caller's SQL via `mdl::transform_sql` regardless of it and re-parses only the
+    /// planned SQL under it. It does not change the formatting of the SQL these methods
+    /// emit — see `dialect::render_in_dialect`.
+    target_dialect: Option<String>,
+    /// Per-query time budget applied in `sql`/`execute_stream`. `None` means no limit.
+    query_timeout: Option<std::time::Duration>,
+    /// Memoizes `transform_sql` results; see `crate::cache`.
+    transform_cache: Arc<TransformSqlCache>,
+    /// The full metadata (param names/types, description, overloads) originally loaded
+    /// for each user-registered remote function, keyed by name. `remote_functions`
+    /// only carries what the core `RemoteFunction` type stores (name/type/return
+    /// type), so `get_available_functions` reads from here instead to avoid losing the
+    /// richer JSON/YAML-sourced metadata on the round trip.
+    remote_function_metadata: Arc<HashMap<String, PyRemoteFunction>>,
 }
 
 impl Hash for PySessionContext {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.mdl.hash(state);
         self.remote_functions.hash(state);
+        // `target_dialect` changes which SQL `pushdown_limit` accepts and which planned
+        // SQL `transform_sql` can re-parse (it does not change their emitted formatting —
+        // see `render_in_dialect`), and
+        // it is already a component of `transform_sql`'s cache key, so it belongs in
+        // this context's identity too.
+        self.target_dialect.hash(state);
     }
 }
 
@@ -56,6 +109,15 @@ impl Default for PySessionContext {
             ctx: wren_core::SessionContext::new(),
             mdl: Arc::new(AnalyzedWrenMDL::default()),
             remote_functions: vec![],
+            runtime: Arc::new(
+                tokio::runtime::Runtime::new().expect("failed to create Tokio runtime"),
+            ),
+            target_dialect: None,
+            query_timeout: None,
+            transform_cache: Arc::new(TransformSqlCache::with_capacity(
+                crate::cache::DEFAULT_TRANSFORM_CACHE_CAPACITY,
+            )),
+            remote_function_metadata: Arc::new(HashMap::new()),
         }
     }
 }
@@ -66,38 +128,81 @@ impl PySessionContext {
     ///
     /// if `mdl_base64` is provided, the session context will be created with the given MDL. Otherwise, an empty MDL will be created.
     /// if `remote_functions_path` is provided, the session context will be created with the remote functions defined in the CSV file.
+    /// if `target_dialect` is provided (e.g. "bigquery", "postgres", "snowflake", "duckdb", "mysql"), `pushdown_limit` parses its input SQL under that dialect instead of the generic one and `transform_sql` re-parses its planned SQL under it; the SQL given to `transform_sql` is parsed without regard to the dialect, and the emitted SQL's formatting does not change.
+    /// if `config` is provided, it bounds execution: memory-pool byte limit, `target_partitions`, and a per-query time budget (see `PySessionConfig`).
     #[new]
-    #[pyo3(signature = (mdl_base64=None, remote_functions_path=None))]
+    #[pyo3(signature = (mdl_base64=None, remote_functions_path=None, target_dialect=None, config=None))]
     pub fn new(
         mdl_base64: Option<&str>,
         remote_functions_path: Option<&str>,
+        target_dialect: Option<&str>,
+        config: Option<PySessionConfig>,
     ) -> PyResult<Self> {
-        let remote_functions = Self::read_remote_function_list(remote_functions_path)
+        // Validate eagerly so a typo'd dialect name fails at construction time.
+        crate::dialect::resolve_dialect(target_dialect)?;
+        let target_dialect = target_dialect.map(str::to_string);
+        let config = config.unwrap_or_default();
+        let query_timeout = config.query_timeout();
+
+        let loaded_remote_functions = Self::read_remote_function_list(remote_functions_path)
             .map_err(CoreError::from)?;
-        let remote_functions: Vec<RemoteFunction> = remote_functions
+        loaded_remote_functions
+            .iter()
+            .try_for_each(crate::remote_functions::validate_param_types)
+            .map_err(PyErr::from)?;
+        let remote_function_metadata = Arc::new(
+            loaded_remote_functions
+                .iter()
+                .map(|f| (f.name.clone(), f.clone()))
+                .collect::<HashMap<String, PyRemoteFunction>>(),
+        );
+        let remote_functions: Vec<RemoteFunction> = loaded_remote_functions
             .into_iter()
-            .map(|f| f.into())
+            .map(RemoteFunction::from)
             .collect::<Vec<_>>();
 
-        let ctx = wren_core::SessionContext::new();
+        let runtime = Arc::new(tokio::runtime::Runtime::new().map_err(CoreError::from)?);
+        let ctx = wren_core::SessionContext::new_with_config_rt(
+            config.to_session_config(),
+            config.to_runtime_env()?,
+        );
+        let transform_cache = Arc::new(TransformSqlCache::with_capacity(
+            config
+                .transform_cache_capacity()
+                .unwrap_or(crate::cache::DEFAULT_TRANSFORM_CACHE_CAPACITY),
+        ));
 
         let Some(mdl_base64) = mdl_base64 else {
             return Ok(Self {
                 ctx,
                 mdl: Arc::new(AnalyzedWrenMDL::default()),
                 remote_functions,
+                runtime,
+                target_dialect,
+                query_timeout,
+                transform_cache,
+                remote_function_metadata,
             });
         };
 
-        let manifest = to_manifest(mdl_base64)?;
-
-        let Ok(analyzed_mdl) = AnalyzedWrenMDL::analyze(manifest) else {
-            return Err(CoreError::new("Failed to analyze manifest").into());
+        // The digest doubles as the process-level cache key, so identical manifests
+        // reuse the analysis even across unrelated `PySessionContext`s, regardless of
+        // how the caller base64-encoded them.
+        let digest = crate::cache::manifest_digest_from_base64(mdl_base64)
+            .map_err(PyErr::from)?;
+        let analyzed_mdl = match crate::cache::get_analyzed_mdl(&digest) {
+            Some(cached) => cached,
+            None => {
+                let manifest = to_manifest(mdl_base64)?;
+                let Ok(analyzed_mdl) = AnalyzedWrenMDL::analyze(manifest) else {
+                    return Err(CoreError::new("Failed to analyze manifest").into());
+                };
+                let analyzed_mdl = Arc::new(analyzed_mdl);
+                crate::cache::put_analyzed_mdl(digest, Arc::clone(&analyzed_mdl));
+                analyzed_mdl
+            }
         };
 
-        let analyzed_mdl = Arc::new(analyzed_mdl);
-
-        let runtime = tokio::runtime::Runtime::new().map_err(CoreError::from)?;
         let ctx = runtime
             .block_on(create_ctx_with_mdl(&ctx, Arc::clone(&analyzed_mdl), false))
             .map_err(CoreError::from)?;
@@ -112,98 +217,235 @@ impl PySessionContext {
             ctx,
             mdl: analyzed_mdl,
             remote_functions,
+            runtime,
+            target_dialect,
+            query_timeout,
+            transform_cache,
+            remote_function_metadata,
         })
     }
 
-    /// Transform the given Wren SQL to the equivalent Planned SQL.
+    /// Transform the given Wren SQL to the equivalent Planned SQL. The given SQL is
+    /// parsed by `mdl::transform_sql` regardless of `target_dialect`; the planned SQL is
+    /// then re-parsed under `target_dialect` if one was set on this context (see
+    /// `render_in_dialect` for exactly what that does and does not change).
+    ///
+    /// Results are memoized in this context's `transform_sql` cache; see `cache_stats`.
     pub fn transform_sql(&self, sql: &str) -> PyResult<String> {
-        mdl::transform_sql(Arc::clone(&self.mdl), &self.remote_functions, sql)
-            .map_err(|e| PyErr::from(CoreError::from(e)))
+        self.transform_cache.get_or_insert_with(
+            crate::cache::stable_hash(&*self.mdl),
+            crate::cache::stable_hash(&self.remote_functions),
+            self.target_dialect.as_deref(),
+            sql,
+            || {
+                let planned_sql =
+                    mdl::transform_sql(Arc::clone(&self.mdl), &self.remote_functions, sql)
+                        .map_err(|e| PyErr::from(CoreError::from(e)))?;
+                render_in_dialect(&planned_sql, self.target_dialect.as_deref())
+            },
+        )
+    }
+
+    /// Snapshot of this context's `transform_sql` cache hit/miss counters, plus the
+    /// size of the process-level `AnalyzedWrenMDL` cache.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.transform_cache.stats()
+    }
+
+    /// Clear this context's `transform_sql` cache. Does not affect the process-level
+    /// `AnalyzedWrenMDL` cache shared with other contexts; use `clear_mdl_cache` for that.
+    pub fn clear_cache(&self) {
+        self.transform_cache.clear();
+    }
+
+    /// Clear the process-level `AnalyzedWrenMDL` cache shared by every `PySessionContext`.
+    #[staticmethod]
+    pub fn clear_mdl_cache() {
+        crate::cache::clear_mdl_cache();
+    }
+
+    /// Register an Arrow `RecordBatch` (or list of batches) as a named in-memory table.
+    ///
+    /// The table is registered against the underlying `wren_core::SessionContext`, so it
+    /// can be queried by `sql` just like a table resolved from the MDL manifest.
+    #[pyo3(signature = (name, batches))]
+    pub fn register_record_batches(
+        &self,
+        name: &str,
+        batches: PyArrowType<Vec<RecordBatch>>,
+    ) -> PyResult<()> {
+        let batches = batches.0;
+        let schema = batches
+            .first()
+            .map(|batch| batch.schema())
+            .ok_or_else(|| CoreError::new("At least one RecordBatch is required"))?;
+        self.register_batches(name, schema, batches)
+    }
+
+    /// Register a PyArrow `Table` (or anything else exporting the Arrow C stream
+    /// interface, e.g. a `RecordBatchReader`) as a named in-memory table.
+    ///
+    /// Unlike `register_record_batches`, this takes the schema from the stream itself,
+    /// so it also accepts an empty table.
+    #[pyo3(signature = (name, table))]
+    pub fn register_table(
+        &self,
+        name: &str,
+        table: PyArrowType<wren_core::arrow::ffi_stream::ArrowArrayStreamReader>,
+    ) -> PyResult<()> {
+        let reader = table.0;
+        let schema = reader.schema();
+        let batches = reader
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(CoreError::from)?;
+        self.register_batches(name, schema, batches)
+    }
+
+    /// Transform the given Wren SQL, plan it against the registered tables, run it to
+    /// completion on the context's Tokio runtime, and return the result as PyArrow
+    /// `RecordBatch` objects.
+    pub fn sql(&self, sql: &str) -> PyResult<PyArrowType<Vec<RecordBatch>>> {
+        let planned_sql = self.transform_sql(sql)?;
+        let ctx = self.ctx.clone();
+        let batches = self.block_on_with_timeout(async move {
+            let df = ctx.sql(&planned_sql).await?;
+            df.collect().await
+        })?;
+        Ok(PyArrowType(batches))
+    }
+
+    /// Same as `sql`, but returns a `RecordBatchStream` that yields batches lazily instead
+    /// of materializing the whole result up front.
+    pub fn execute_stream(&self, sql: &str) -> PyResult<PyRecordBatchStream> {
+        let planned_sql = self.transform_sql(sql)?;
+        // Computed once here, before planning, not re-derived from `query_timeout` on
+        // every poll, so planning and the whole stream share one budget instead of each
+        // batch getting a fresh timeout.
+        let deadline = self
+            .query_timeout
+            .map(|timeout| std::time::Instant::now() + timeout);
+        let ctx = self.ctx.clone();
+        let stream = self.block_on_with_timeout(async move {
+            let df = ctx.sql(&planned_sql).await?;
+            df.execute_stream().await
+        })?;
+        Ok(PyRecordBatchStream {
+            stream,
+            runtime: Arc::clone(&self.runtime),
+            deadline,
+        })
     }
 
     /// Get the available functions in the session context.
     pub fn get_available_functions(&self) -> PyResult<Vec<PyRemoteFunction>> {
-        let mut builder = self
-            .remote_functions
-            .iter()
-            .map(|f| (f.name.clone(), f.clone().into()))
-            .collect::<HashMap<String, PyRemoteFunction>>();
+        // Read from the originally-loaded metadata, not `self.remote_functions` (the
+        // core `RemoteFunction`), so user-registered functions keep their param
+        // names/types, description, and overloads instead of coming back name-only.
+        let mut builder = (*self.remote_function_metadata).clone();
         self.ctx
             .state()
             .scalar_functions()
             .iter()
-            .for_each(|(name, _func)| {
-                match builder.entry(name.clone()) {
-                    Entry::Occupied(_) => {}
-                    Entry::Vacant(entry) => {
-                        entry.insert(PyRemoteFunction {
-                            function_type: "scalar".to_string(),
-                            name: name.clone(),
-                            // TODO: get function return type from SessionState
-                            return_type: None,
-                            param_names: None,
-                            param_types: None,
-                            description: None,
-                        });
-                    }
+            .for_each(|(name, func)| {
+                if let Entry::Vacant(entry) = builder.entry(name.clone()) {
+                    entry.insert(PyRemoteFunction {
+                        function_type: "scalar".to_string(),
+                        name: name.clone(),
+                        return_type: func.return_type(&[]).ok().map(|t| t.to_string()),
+                        // DataFusion signatures don't carry parameter names.
+                        param_names: None,
+                        param_types: signature_param_types(func.signature()),
+                        description: func.documentation().map(|doc| doc.description.to_string()),
+                        overloads: vec![],
+                    });
                 }
             });
         self.ctx
             .state()
             .aggregate_functions()
             .iter()
-            .for_each(|(name, _func)| {
-                match builder.entry(name.clone()) {
-                    Entry::Occupied(_) => {}
-                    Entry::Vacant(entry) => {
-                        entry.insert(PyRemoteFunction {
-                            function_type: "aggregate".to_string(),
-                            name: name.clone(),
-                            // TODO: get function return type from SessionState
-                            return_type: None,
-                            param_names: None,
-                            param_types: None,
-                            description: None,
-                        });
-                    }
+            .for_each(|(name, func)| {
+                if let Entry::Vacant(entry) = builder.entry(name.clone()) {
+                    entry.insert(PyRemoteFunction {
+                        function_type: "aggregate".to_string(),
+                        name: name.clone(),
+                        return_type: func.return_type(&[]).ok().map(|t| t.to_string()),
+                        param_names: None,
+                        param_types: signature_param_types(func.signature()),
+                        description: func.documentation().map(|doc| doc.description.to_string()),
+                        overloads: vec![],
+                    });
                 }
             });
         self.ctx
             .state()
             .window_functions()
             .iter()
-            .for_each(|(name, _func)| {
-                match builder.entry(name.clone()) {
-                    Entry::Occupied(_) => {}
-                    Entry::Vacant(entry) => {
-                        entry.insert(PyRemoteFunction {
-                            function_type: "window".to_string(),
-                            name: name.clone(),
-                            // TODO: get function return type from SessionState
-                            return_type: None,
-                            param_names: None,
-                            param_types: None,
-                            description: None,
-                        });
-                    }
+            .for_each(|(name, func)| {
+                if let Entry::Vacant(entry) = builder.entry(name.clone()) {
+                    entry.insert(PyRemoteFunction {
+                        function_type: "window".to_string(),
+                        name: name.clone(),
+                        return_type: func.return_type(&[]).ok().map(|t| t.to_string()),
+                        param_names: None,
+                        param_types: signature_param_types(func.signature()),
+                        description: func.documentation().map(|doc| doc.description.to_string()),
+                        overloads: vec![],
+                    });
                 }
             });
         Ok(builder.values().cloned().collect())
     }
 
+    /// The wren-core and DataFusion versions this build was compiled against, as
+    /// `(wren_core_version, datafusion_version)`.
+    pub fn version(&self) -> (String, String) {
+        (
+            wren_core::VERSION.to_string(),
+            wren_core::datafusion::DATAFUSION_VERSION.to_string(),
+        )
+    }
+
+    /// Report this build's versions, supported SQL dialects, the MDL manifest schema
+    /// version it was compiled against, and whether optional features are enabled, so
+    /// a caller can check compatibility before issuing commands.
+    pub fn capabilities(&self) -> PyCapabilities {
+        PyCapabilities {
+            wren_core_version: wren_core::VERSION.to_string(),
+            datafusion_version: wren_core::datafusion::DATAFUSION_VERSION.to_string(),
+            supported_dialects: crate::dialect::SUPPORTED_DIALECTS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            mdl_manifest_schema_version: MDL_MANIFEST_SCHEMA_VERSION.to_string(),
+            execution_enabled: true,
+            remote_functions_enabled: true,
+        }
+    }
+
     /// Push down the limit to the given SQL.
     /// If the limit is None, the SQL will be returned as is.
     /// If the limit is greater than the pushdown limit, the limit will be replaced with the pushdown limit.
     /// Otherwise, the limit will be kept as is.
-    #[pyo3(signature = (sql, limit=None))]
-    pub fn pushdown_limit(&self, sql: &str, limit: Option<usize>) -> PyResult<String> {
+    /// `dialect` overrides this context's `target_dialect` for this call only (affecting
+    /// only which input SQL syntax is accepted, not the formatting of the returned SQL);
+    /// when neither is set, `GenericDialect` is used.
+    #[pyo3(signature = (sql, limit=None, dialect=None))]
+    pub fn pushdown_limit(
+        &self,
+        sql: &str,
+        limit: Option<usize>,
+        dialect: Option<&str>,
+    ) -> PyResult<String> {
         if limit.is_none() {
             return Ok(sql.to_string());
         }
         let pushdown = limit.unwrap();
-        let mut statements =
-            wren_core::parser::Parser::parse_sql(&GenericDialect {}, sql)
-                .map_err(CoreError::from)?;
+        let dialect = crate::dialect::resolve_dialect(
+            dialect.or(self.target_dialect.as_deref()),
+        )?;
+        let mut statements = wren_core::parser::Parser::parse_sql(dialect.as_ref(), sql)
+            .map_err(CoreError::from)?;
         if statements.len() != 1 {
             return Err(CoreError::new("Only one statement is allowed").into());
         }
@@ -229,7 +471,81 @@ impl PySessionContext {
     }
 }
 
+/// Run `fut` to completion on `runtime`, aborting with a `CoreError` if it exceeds
+/// `timeout`. Shared by `PySessionContext::sql` (bounding planning and collecting the
+/// whole result), `PySessionContext::execute_stream` (bounding planning) and
+/// `PyRecordBatchStream::__next__` (bounding each batch of a streamed query), so
+/// `query_timeout_secs` actually covers execution, not just planning.
+fn run_with_timeout<F, T>(
+    runtime: &tokio::runtime::Runtime,
+    timeout: Option<std::time::Duration>,
+    fut: F,
+) -> PyResult<T>
+where
+    F: std::future::Future<Output = wren_core::datafusion::error::Result<T>>,
+{
+    runtime
+        .block_on(async {
+            match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, fut)
+                    .await
+                    .map_err(|_| {
+                        CoreError::new("Query exceeded the configured time budget")
+                    })?
+                    .map_err(CoreError::from),
+                None => fut.await.map_err(CoreError::from),
+            }
+        })
+        .map_err(PyErr::from)
+}
+
+/// Extract parameter types from a DataFusion `Signature`, when it declares an exact
+/// fixed-arity shape. Variadic/uniform/"any" signatures don't have a fixed parameter
+/// list, so those return `None` rather than guessing.
+fn signature_param_types(signature: &wren_core::datafusion::logical_expr::Signature) -> Option<Vec<String>> {
+    match &signature.type_signature {
+        TypeSignature::Exact(types) => Some(types.iter().map(|t| t.to_string()).collect()),
+        _ => None,
+    }
+}
+
 impl PySessionContext {
+    /// Run `fut` to completion on this context's Tokio runtime, aborting with a
+    /// `CoreError` if it exceeds the configured `query_timeout`.
+    fn block_on_with_timeout<F, T>(&self, fut: F) -> PyResult<T>
+    where
+        F: std::future::Future<Output = wren_core::datafusion::error::Result<T>>,
+    {
+        run_with_timeout(&self.runtime, self.query_timeout, fut)
+    }
+
+    /// Register `batches` under `name` as a `MemTable`, shared by
+    /// `register_record_batches` and `register_table` once each has settled on a
+    /// schema for its own input shape.
+    fn register_batches(
+        &self,
+        name: &str,
+        schema: wren_core::arrow::datatypes::SchemaRef,
+        batches: Vec<RecordBatch>,
+    ) -> PyResult<()> {
+        let table = wren_core::datafusion::datasource::MemTable::try_new(schema, vec![batches])
+            .map_err(CoreError::from)?;
+        self.ctx
+            .register_table(name, Arc::new(table))
+            .map_err(CoreError::from)
+            .map(|_| ())?;
+        Ok(())
+    }
+
+    // NOTE: `ByPass*UDF::new` (in `wren_core::mdl::function`) only accepts a name and a
+    // single return type today, so a fully overloaded `Signature` derived from a
+    // JSON/YAML function's `param_types` can't be threaded through here until
+    // `wren_core` grows a constructor that takes one. `new` validates every declared
+    // param/overload type via `remote_functions::validate_param_types` before this is
+    // called, and `get_available_functions` reads the original rich metadata back from
+    // `remote_function_metadata` rather than reconstructing it from the `RemoteFunction`
+    // registered below, so the loaded signatures aren't entirely discarded even though
+    // execution can't yet use them.
     fn register_remote_function(
         ctx: &wren_core::SessionContext,
         remote_function: &RemoteFunction,
@@ -260,19 +576,106 @@ impl PySessionContext {
         Ok(())
     }
 
+    /// Read a remote function list from `path`. CSV files are loaded directly; JSON
+    /// and YAML files go through `remote_functions::read_remote_function_list_from_file`,
+    /// which also carries parameter names/types, a description, and overloads.
     fn read_remote_function_list(path: Option<&str>) -> PyResult<Vec<PyRemoteFunction>> {
         debug!(
             "Reading remote function list from {}",
             path.unwrap_or("path is not provided")
         );
-        if let Some(path) = path {
+        let Some(path) = path else {
+            return Ok(vec![]);
+        };
+        if path.ends_with(".csv") {
             Ok(csv::Reader::from_path(path)
                 .map_err(CoreError::from)?
                 .into_deserialize::<PyRemoteFunction>()
                 .filter_map(Result::ok)
                 .collect::<Vec<_>>())
         } else {
-            Ok(vec![])
+            Ok(crate::remote_functions::read_remote_function_list_from_file(path)?)
         }
     }
 }
+
+/// A PyArrow-compatible iterator over the batches of an in-flight query.
+///
+/// Returned by `PySessionContext::execute_stream` so large results can be consumed
+/// incrementally instead of being collected into memory all at once.
+#[pyclass(name = "RecordBatchStream")]
+pub struct PyRecordBatchStream {
+    stream: SendableRecordBatchStream,
+    runtime: Arc<tokio::runtime::Runtime>,
+    /// Derived from the `PySessionContext`'s `query_timeout` once, in `execute_stream`
+    /// before the query is planned. Each `__next__` poll is bounded by the *remaining* time until this
+    /// deadline, not a fresh `query_timeout` of its own, so the whole streamed query
+    /// shares one budget the same way `sql`'s single `collect()` call does.
+    deadline: Option<std::time::Instant>,
+}
+
+#[pymethods]
+impl PyRecordBatchStream {
+    fn __iter__(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(
+        mut slf: pyo3::PyRefMut<'_, Self>,
+    ) -> PyResult<Option<PyArrowType<RecordBatch>>> {
+        let runtime = Arc::clone(&slf.runtime);
+        let remaining = slf
+            .deadline
+            .map(|deadline| deadline.saturating_duration_since(std::time::Instant::now()));
+        let batch = run_with_timeout(&runtime, remaining, async {
+            futures::StreamExt::next(&mut slf.stream).await.transpose()
+        })?;
+        Ok(batch.map(PyArrowType))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_record_batches_rejects_an_empty_list() {
+        let ctx = PySessionContext::default();
+        let err = ctx
+            .register_record_batches("t", PyArrowType(vec![]))
+            .unwrap_err();
+        assert!(err.to_string().contains("At least one RecordBatch is required"));
+    }
+
+    #[test]
+    fn sql_runs_against_a_registered_record_batch() {
+        use wren_core::arrow::array::Int32Array;
+        use wren_core::arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )
+        .unwrap();
+
+        let ctx = PySessionContext::default();
+        ctx.register_record_batches("numbers", PyArrowType(vec![batch]))
+            .unwrap();
+
+        let PyArrowType(batches) = ctx.sql("SELECT id FROM numbers ORDER BY id").unwrap();
+        let ids: Vec<i32> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::errors::CoreError;
+use pyo3::PyResult;
+use wren_core::dialect::{
+    BigQueryDialect, Dialect, DuckDbDialect, GenericDialect, MySqlDialect,
+    PostgreSqlDialect, SnowflakeDialect,
+};
+
+/// The `target_dialect` names accepted by `PySessionContext`, in addition to the
+/// implicit default (`GenericDialect`) used when no dialect is given.
+pub const SUPPORTED_DIALECTS: &[&str] =
+    &["bigquery", "postgres", "snowflake", "duckdb", "mysql"];
+
+/// Resolve a user-facing dialect name (e.g. "bigquery") to the matching `sqlparser`
+/// dialect. `None` falls back to `GenericDialect`, and an unrecognized name is a
+/// `CoreError` rather than a silent fallback, since it usually means a caller typo'd
+/// the warehouse name.
+pub fn resolve_dialect(name: Option<&str>) -> PyResult<Box<dyn Dialect>> {
+    let dialect: Box<dyn Dialect> = match name.map(str::to_ascii_lowercase).as_deref() {
+        None => Box::new(GenericDialect {}),
+        Some("bigquery") => Box::new(BigQueryDialect {}),
+        Some("postgres") | Some("postgresql") => Box::new(PostgreSqlDialect {}),
+        Some("snowflake") => Box::new(SnowflakeDialect {}),
+        Some("duckdb") => Box::new(DuckDbDialect {}),
+        Some("mysql") => Box::new(MySqlDialect {}),
+        Some(other) => {
+            return Err(CoreError::new(format!(
+                "Unsupported target dialect: {other}, expected one of {SUPPORTED_DIALECTS:?}"
+            ))
+            .into())
+        }
+    };
+    Ok(dialect)
+}
+
+/// Re-parse `sql` under the given dialect and re-render it.
+///
+/// This only changes what input syntax is *accepted* while parsing (identifier start
+/// characters, keyword sets, which quote characters are recognized) — `sqlparser`'s
+/// `Display` impls for `Statement`/`Expr`/`Ident` are dialect-agnostic, so the
+/// re-rendered SQL is byte-identical across dialects for any input that parses under
+/// both. It does not produce BigQuery- or MySQL-specific quoting, `LIMIT`/`OFFSET`
+/// syntax, or function names; it exists to validate `sql` parses under
+/// `target_dialect` and to normalize it (e.g. stripping comments/extra whitespace), not
+/// to transpile it.
+pub fn render_in_dialect(sql: &str, name: Option<&str>) -> PyResult<String> {
+    let dialect = resolve_dialect(name)?;
+    let statements = wren_core::parser::Parser::parse_sql(dialect.as_ref(), sql)
+        .map_err(CoreError::from)?;
+    Ok(statements
+        .iter()
+        .map(|statement| statement.to_string())
+        .collect::<Vec<_>>()
+        .join("; "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_dialect_name_is_rejected() {
+        assert!(resolve_dialect(Some("oracle")).is_err());
+    }
+
+    #[test]
+    fn render_in_dialect_does_not_change_limit_offset_or_quoting_across_dialects() {
+        // Documents the real, current behavior: re-rendering via `Statement::to_string()`
+        // is dialect-agnostic, so a `target_dialect` only changes which input syntax
+        // parses, never the emitted SQL. If this ever stops being true (e.g. after
+        // switching to a dialect-aware unparser), this test should start failing and
+        // needs to be updated alongside the doc comment above.
+        let sql = r#"SELECT "id" FROM "t" LIMIT 10 OFFSET 5"#;
+        let bigquery = render_in_dialect(sql, Some("bigquery")).unwrap();
+        let mysql = render_in_dialect(sql, Some("mysql")).unwrap();
+        let generic = render_in_dialect(sql, None).unwrap();
+        assert_eq!(bigquery, mysql);
+        assert_eq!(bigquery, generic);
+    }
+}
@@ -0,0 +1,147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::errors::CoreError;
+use pyo3::pyclass;
+use serde::{Deserialize, Serialize};
+use wren_core::logical_plan::utils::map_data_type;
+use wren_core::mdl::function::{FunctionType, RemoteFunction};
+
+/// One overload of a remote function's call signature: its parameter names/types and
+/// the return type for that arity. A function with `func(a INT)` and `func(a INT, b
+/// INT)` overloads gets one `FunctionOverload` each.
+#[pyclass(name = "FunctionOverload")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PyFunctionOverload {
+    #[pyo3(get)]
+    pub param_names: Vec<String>,
+    #[pyo3(get)]
+    pub param_types: Vec<String>,
+    #[pyo3(get)]
+    pub return_type: String,
+}
+
+/// The Python-facing view of a function available in a session context, covering both
+/// user-registered remote functions and DataFusion's built-ins.
+#[pyclass(name = "RemoteFunction")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PyRemoteFunction {
+    #[pyo3(get)]
+    pub function_type: String,
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub return_type: Option<String>,
+    #[pyo3(get)]
+    pub param_names: Option<Vec<String>>,
+    #[pyo3(get)]
+    pub param_types: Option<Vec<String>>,
+    #[pyo3(get)]
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Additional overloaded signatures beyond the primary `param_types`/`return_type`,
+    /// only ever populated by the JSON/YAML loader (the CSV format has no way to
+    /// express more than one signature per row).
+    #[pyo3(get)]
+    #[serde(default)]
+    pub overloads: Vec<PyFunctionOverload>,
+}
+
+impl From<RemoteFunction> for PyRemoteFunction {
+    fn from(remote_function: RemoteFunction) -> Self {
+        let function_type = match remote_function.function_type {
+            FunctionType::Scalar => "scalar",
+            FunctionType::Aggregate => "aggregate",
+            FunctionType::Window => "window",
+        }
+        .to_string();
+        Self {
+            function_type,
+            name: remote_function.name,
+            return_type: Some(remote_function.return_type),
+            param_names: None,
+            param_types: None,
+            description: None,
+            overloads: vec![],
+        }
+    }
+}
+
+impl From<&PyRemoteFunction> for RemoteFunction {
+    fn from(py_remote_function: &PyRemoteFunction) -> Self {
+        let function_type = match py_remote_function.function_type.to_ascii_lowercase().as_str()
+        {
+            "aggregate" => FunctionType::Aggregate,
+            "window" => FunctionType::Window,
+            _ => FunctionType::Scalar,
+        };
+        Self {
+            function_type,
+            name: py_remote_function.name.clone(),
+            return_type: py_remote_function.return_type.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<PyRemoteFunction> for RemoteFunction {
+    fn from(py_remote_function: PyRemoteFunction) -> Self {
+        RemoteFunction::from(&py_remote_function)
+    }
+}
+
+/// Validate every parameter type a `PyRemoteFunction` declares (its primary
+/// `param_types` plus every overload's), so a bad type name surfaces at registration
+/// time instead of silently being dropped. This is as far as loaded signatures can be
+/// threaded into registration today: `ByPassScalarUDF`/`ByPassAggregateUDF`/
+/// `ByPassWindowFunction` (in `wren_core::mdl::function`) only accept a name and a
+/// single return type, so there is no constructor to hand a real multi-arg `Signature`
+/// or the overload list to — doing so needs a `wren_core` change outside this crate.
+/// For the same reason, `From<&PyRemoteFunction> for RemoteFunction` below only carries
+/// the primary `return_type` forward: a function with multiple overloaded signatures is
+/// still registered (and callable) under one signature only, even though every overload
+/// was validated here and is reported back by `get_available_functions` — the overload
+/// list is descriptive metadata, not yet something registration can act on.
+pub fn validate_param_types(remote_function: &PyRemoteFunction) -> Result<(), CoreError> {
+    let param_type_lists = remote_function
+        .param_types
+        .iter()
+        .chain(remote_function.overloads.iter().map(|o| &o.param_types));
+    for param_types in param_type_lists {
+        for param_type in param_types {
+            map_data_type(param_type).map_err(CoreError::from)?;
+        }
+    }
+    for overload in &remote_function.overloads {
+        map_data_type(&overload.return_type).map_err(CoreError::from)?;
+    }
+    Ok(())
+}
+
+/// Read a remote function list from a JSON or YAML file, keyed by file extension.
+///
+/// Unlike the CSV format, JSON/YAML can describe per-function parameter names and
+/// types, a description, and multiple overloaded signatures.
+pub fn read_remote_function_list_from_file(path: &str) -> Result<Vec<PyRemoteFunction>, CoreError> {
+    let contents = std::fs::read_to_string(path).map_err(CoreError::from)?;
+    match path.rsplit('.').next().map(str::to_ascii_lowercase).as_deref() {
+        Some("json") => serde_json::from_str(&contents).map_err(CoreError::from),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&contents).map_err(CoreError::from),
+        other => Err(CoreError::new(format!(
+            "Unsupported remote function list format: {other:?}, expected .csv, .json, .yaml, or .yml"
+        ))),
+    }
+}